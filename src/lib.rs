@@ -1,4 +1,32 @@
-use std::ops::{BitAndAssign, BitOrAssign};
+use std::fmt;
+use std::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not,
+};
+
+/// The error returned when a [`Bitmap`] cannot be built from a raw byte buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BitmapError {
+    /// The supplied buffer length does not match `bit_count.div_ceil(8)`.
+    LengthMismatch {
+        /// The number of bytes required to hold `bit_count` bits.
+        expected: usize,
+        /// The number of bytes actually supplied.
+        found: usize,
+    },
+}
+
+impl fmt::Display for BitmapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BitmapError::LengthMismatch { expected, found } => write!(
+                f,
+                "byte buffer length {found} does not match the {expected} bytes required"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BitmapError {}
 
 
 /// A bitmap data structure that stores bits in a vector of u8 integers.
@@ -15,8 +43,8 @@ use std::ops::{BitAndAssign, BitOrAssign};
 /// bitmap.unset(5);
 /// assert_eq!(bitmap.get(5), false);
 /// ```
-/// 
-
+///
+#[derive(Debug)]
 pub struct Bitmap {
     /// The capacity of the underlying vector in terms of 8-bit chunks.
     bitmap_capacity: usize,
@@ -24,6 +52,12 @@ pub struct Bitmap {
     /// The total number of bits in the bitmap.
     bit_count: usize,
 
+    /// The running count of bits currently set to 1.
+    ///
+    /// Kept in sync by every mutating operation so `count_ones`/`is_empty`
+    /// are `O(1)` and never rescan `map`.
+    len: usize,
+
     /// The underlying vector storing the bitmap data.
     map: Vec<u8>
 }
@@ -53,10 +87,66 @@ impl Bitmap {
         Self {
             bit_count: bit_count,
             bitmap_capacity: bitmap_capacity,
+            len: 0,
             map: map
         }
     }
 
+    /// Builds a bitmap directly from an existing byte buffer.
+    ///
+    /// The buffer is taken by value and used as the backing store without a
+    /// copy, so bitmaps can be built straight from I/O or a memory-mapped
+    /// region. Any bits set in the final byte beyond `bit_count` are zeroed
+    /// so every invariant the rest of the API relies on holds.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The backing buffer, which must be exactly
+    ///   `bit_count.div_ceil(8)` bytes long.
+    /// * `bit_count` - The total number of bits in the bitmap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_count` is zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BitmapError::LengthMismatch`] if `bytes.len()` does not equal
+    /// `bit_count.div_ceil(8)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let bitmap = bitmap::Bitmap::try_from_bytes(vec![0b0000_0101], 8).unwrap();
+    /// assert_eq!(bitmap.get(0), true);
+    /// assert_eq!(bitmap.get(2), true);
+    /// ```
+    ///
+    pub fn try_from_bytes(mut bytes: Vec<u8>, bit_count: usize) -> Result<Self, BitmapError> {
+        assert!(bit_count > 0);
+        let bitmap_capacity = bit_count.div_ceil(8);
+        if bytes.len() != bitmap_capacity {
+            return Err(BitmapError::LengthMismatch {
+                expected: bitmap_capacity,
+                found: bytes.len(),
+            });
+        }
+        let tail_mask = match bit_count % 8 {
+            0 => 0xFF,
+            rem => (1 << rem) - 1,
+        };
+        if let Some(last) = bytes.last_mut() {
+            last.bitand_assign(tail_mask);
+        }
+        let len = bytes.iter().map(|byte| byte.count_ones()).sum::<u32>() as usize;
+        Ok(Self {
+            bit_count,
+            bitmap_capacity,
+            len,
+            map: bytes,
+        })
+    }
+
     /// Sets the bit at the specified index to 1.
     ///
     /// # Arguments
@@ -67,17 +157,29 @@ impl Bitmap {
     ///
     /// Panics if `bit_index` is greater than or equal to `bit_count`.
     ///
+    /// # Returns
+    ///
+    /// The previous value of the bit: `true` if it was already set.
+    ///
     /// # Examples
     ///
     /// ```
     /// let mut bitmap = bitmap::Bitmap::new(10);
-    /// bitmap.set(5);
+    /// assert_eq!(bitmap.set(5), false);
+    /// assert_eq!(bitmap.set(5), true);
     /// assert_eq!(bitmap.get(5), true);
     /// ```
-    /// 
-    pub fn set(&mut self, bit_index: usize) {
+    ///
+    pub fn set(&mut self, bit_index: usize) -> bool {
         assert!(bit_index < self.bit_count);
-        self.map[bit_index / 8].bitor_assign(1 << (bit_index % 8));
+        let mask = 1 << (bit_index % 8);
+        let byte = &mut self.map[bit_index / 8];
+        let previous = *byte & mask == mask;
+        byte.bitor_assign(mask);
+        if !previous {
+            self.len += 1;
+        }
+        previous
     }
 
     /// Unsets the bit at the specified index to 0.
@@ -95,13 +197,172 @@ impl Bitmap {
     /// ```
     /// let mut bitmap = bitmap::Bitmap::new(10);
     /// bitmap.set(5);
-    /// bitmap.unset(5);
+    /// assert_eq!(bitmap.unset(5), true);
+    /// assert_eq!(bitmap.unset(5), false);
     /// assert_eq!(bitmap.get(5), false);
     /// ```
-    /// 
-    pub fn unset(&mut self, bit_index: usize) {
+    ///
+    /// # Returns
+    ///
+    /// The previous value of the bit: `true` if it was set.
+    pub fn unset(&mut self, bit_index: usize) -> bool {
+        assert!(bit_index < self.bit_count);
+        let mask = 1 << (bit_index % 8);
+        let byte = &mut self.map[bit_index / 8];
+        let previous = *byte & mask == mask;
+        byte.bitand_assign(!mask);
+        if previous {
+            self.len -= 1;
+        }
+        previous
+    }
+
+    /// Sets every bit in the half-open range `start..end` to 1.
+    ///
+    /// Whole bytes in the middle of the range are written in a single `0xFF`
+    /// store, so filling a large contiguous range is `O(bytes)` rather than
+    /// `O(bits)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The inclusive lower bound of the range.
+    /// * `end` - The exclusive upper bound of the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start > end` or `end > bit_count`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut bitmap = bitmap::Bitmap::new(32);
+    /// bitmap.set_range(4, 20);
+    /// assert_eq!(bitmap.get(4), true);
+    /// assert_eq!(bitmap.get(19), true);
+    /// assert_eq!(bitmap.get(20), false);
+    /// ```
+    ///
+    pub fn set_range(&mut self, start: usize, end: usize) {
+        assert!(start <= end);
+        assert!(end <= self.bit_count);
+        if start == end {
+            return;
+        }
+        for byte in (start / 8)..=((end - 1) / 8) {
+            let before = self.map[byte].count_ones();
+            self.map[byte].bitor_assign(Self::range_mask(start, end, byte));
+            self.len += (self.map[byte].count_ones() - before) as usize;
+        }
+    }
+
+    /// Unsets every bit in the half-open range `start..end` to 0.
+    ///
+    /// Whole bytes in the middle of the range are written in a single `0x00`
+    /// store, so clearing a large contiguous range is `O(bytes)` rather than
+    /// `O(bits)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The inclusive lower bound of the range.
+    /// * `end` - The exclusive upper bound of the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start > end` or `end > bit_count`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut bitmap = bitmap::Bitmap::new(32);
+    /// bitmap.set_range(0, 32);
+    /// bitmap.unset_range(4, 20);
+    /// assert_eq!(bitmap.get(3), true);
+    /// assert_eq!(bitmap.get(4), false);
+    /// assert_eq!(bitmap.get(20), true);
+    /// ```
+    ///
+    pub fn unset_range(&mut self, start: usize, end: usize) {
+        assert!(start <= end);
+        assert!(end <= self.bit_count);
+        if start == end {
+            return;
+        }
+        for byte in (start / 8)..=((end - 1) / 8) {
+            let before = self.map[byte].count_ones();
+            self.map[byte].bitand_assign(!Self::range_mask(start, end, byte));
+            self.len -= (before - self.map[byte].count_ones()) as usize;
+        }
+    }
+
+    /// Toggles the bit at the specified index.
+    ///
+    /// # Arguments
+    ///
+    /// * `bit_index` - The index of the bit to flip.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_index` is greater than or equal to `bit_count`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut bitmap = bitmap::Bitmap::new(10);
+    /// bitmap.flip(5);
+    /// assert_eq!(bitmap.get(5), true);
+    /// bitmap.flip(5);
+    /// assert_eq!(bitmap.get(5), false);
+    /// ```
+    ///
+    pub fn flip(&mut self, bit_index: usize) {
         assert!(bit_index < self.bit_count);
-        self.map[bit_index / 8].bitand_assign(!(1 << (bit_index % 8)));
+        let mask = 1 << (bit_index % 8);
+        let byte = &mut self.map[bit_index / 8];
+        let was_set = *byte & mask == mask;
+        byte.bitxor_assign(mask);
+        if was_set {
+            self.len -= 1;
+        } else {
+            self.len += 1;
+        }
+    }
+
+    /// Toggles every bit in the half-open range `start..end`.
+    ///
+    /// Whole bytes in the middle of the range are flipped with a single
+    /// `^= 0xFF` store, so flipping a large contiguous range is `O(bytes)`
+    /// rather than `O(bits)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The inclusive lower bound of the range.
+    /// * `end` - The exclusive upper bound of the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start > end` or `end > bit_count`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut bitmap = bitmap::Bitmap::new(32);
+    /// bitmap.set(5);
+    /// bitmap.flip_range(4, 8);
+    /// assert_eq!(bitmap.get(4), true);
+    /// assert_eq!(bitmap.get(5), false);
+    /// ```
+    ///
+    pub fn flip_range(&mut self, start: usize, end: usize) {
+        assert!(start <= end);
+        assert!(end <= self.bit_count);
+        if start == end {
+            return;
+        }
+        for byte in (start / 8)..=((end - 1) / 8) {
+            let before = self.map[byte].count_ones();
+            self.map[byte].bitxor_assign(Self::range_mask(start, end, byte));
+            self.len = self.len - before as usize + self.map[byte].count_ones() as usize;
+        }
     }
 
     /// Returns the value of the bit at the specified index.
@@ -159,11 +420,457 @@ impl Bitmap {
     pub fn get_bitmap_capacity(&self) -> usize {
         self.bitmap_capacity
     }
+
+    /// Returns the underlying byte buffer as a slice.
+    ///
+    /// This is the inverse of [`Bitmap::try_from_bytes`] and hands the backing
+    /// store to I/O without a copy. The padding bits above `bit_count` in the
+    /// final byte are always 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut bitmap = bitmap::Bitmap::new(8);
+    /// bitmap.set(0);
+    /// bitmap.set(2);
+    /// assert_eq!(bitmap.as_slice(), &[0b0000_0101]);
+    /// ```
+    ///
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.map
+    }
+
+    /// Returns the number of bits set to 1 in the bitmap.
+    ///
+    /// This reads the running `len` counter, so it is `O(1)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut bitmap = bitmap::Bitmap::new(10);
+    /// bitmap.set(1);
+    /// bitmap.set(7);
+    /// assert_eq!(bitmap.count_ones(), 2);
+    /// ```
+    ///
+    #[inline]
+    pub fn count_ones(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no bits are set to 1.
+    ///
+    /// This reads the running `len` counter, so it is `O(1)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut bitmap = bitmap::Bitmap::new(10);
+    /// assert_eq!(bitmap.is_empty(), true);
+    /// bitmap.set(3);
+    /// assert_eq!(bitmap.is_empty(), false);
+    /// ```
+    ///
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the lowest index of a bit set to 1, or `None` if the bitmap is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut bitmap = bitmap::Bitmap::new(64);
+    /// bitmap.set(7);
+    /// bitmap.set(40);
+    /// assert_eq!(bitmap.first_index(), Some(7));
+    /// ```
+    ///
+    pub fn first_index(&self) -> Option<usize> {
+        self.iter_ones().next()
+    }
+
+    /// Returns the highest index of a bit set to 1, or `None` if the bitmap is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut bitmap = bitmap::Bitmap::new(64);
+    /// bitmap.set(7);
+    /// bitmap.set(40);
+    /// assert_eq!(bitmap.last_index(), Some(40));
+    /// ```
+    ///
+    pub fn last_index(&self) -> Option<usize> {
+        let mask = self.tail_mask();
+        for byte_index in (0..self.map.len()).rev() {
+            let mut byte = self.map[byte_index];
+            if byte_index == self.map.len() - 1 {
+                byte &= mask;
+            }
+            if byte != 0 {
+                return Some(byte_index * 8 + (7 - byte.leading_zeros() as usize));
+            }
+        }
+        None
+    }
+
+    /// Returns the number of bits set to 0 in the bitmap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut bitmap = bitmap::Bitmap::new(10);
+    /// bitmap.set(1);
+    /// assert_eq!(bitmap.count_zeros(), 9);
+    /// ```
+    ///
+    pub fn count_zeros(&self) -> usize {
+        self.bit_count - self.count_ones()
+    }
+
+    /// Returns the number of bits set to 1 in the indices `0..=bit_index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bit_index` - The inclusive upper bound of the rank query.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_index` is greater than or equal to `bit_count`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut bitmap = bitmap::Bitmap::new(10);
+    /// bitmap.set(1);
+    /// bitmap.set(7);
+    /// assert_eq!(bitmap.rank(7), 2);
+    /// assert_eq!(bitmap.rank(5), 1);
+    /// ```
+    ///
+    pub fn rank(&self, bit_index: usize) -> usize {
+        assert!(bit_index < self.bit_count);
+        let byte = bit_index / 8;
+        let bit = bit_index % 8;
+        let mut count: u32 = self.map[..byte].iter().map(|b| b.count_ones()).sum();
+        let mask: u8 = if bit == 7 { 0xFF } else { (1 << (bit + 1)) - 1 };
+        count += (self.map[byte] & mask).count_ones();
+        count as usize
+    }
+
+    /// Returns the index of the `n`-th (0-based) bit set to 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The zero-based rank of the set bit to locate.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(index)` of the `n`-th set bit.
+    /// * `None` if fewer than `n + 1` bits are set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut bitmap = bitmap::Bitmap::new(10);
+    /// bitmap.set(1);
+    /// bitmap.set(7);
+    /// assert_eq!(bitmap.select(0), Some(1));
+    /// assert_eq!(bitmap.select(1), Some(7));
+    /// assert_eq!(bitmap.select(2), None);
+    /// ```
+    ///
+    pub fn select(&self, n: usize) -> Option<usize> {
+        let mut remaining = n;
+        for (byte_index, &byte) in self.map.iter().enumerate() {
+            let ones = byte.count_ones() as usize;
+            if ones > remaining {
+                let mut byte = byte;
+                for _ in 0..remaining {
+                    byte &= byte - 1;
+                }
+                return Some(byte_index * 8 + byte.trailing_zeros() as usize);
+            }
+            remaining -= ones;
+        }
+        None
+    }
+
+    /// Returns an iterator over the indices of all bits set to 1.
+    ///
+    /// The iterator skips zero bytes wholesale and yields set bits in
+    /// ascending order, so enumeration is `O(set bits)` rather than
+    /// `O(bit_count)`. Padding bits above `bit_count` are never produced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut bitmap = bitmap::Bitmap::new(10);
+    /// bitmap.set(1);
+    /// bitmap.set(7);
+    /// let ones: Vec<usize> = bitmap.iter_ones().collect();
+    /// assert_eq!(ones, vec![1, 7]);
+    /// ```
+    ///
+    pub fn iter_ones(&self) -> IterOnes<'_> {
+        let tail_mask = self.tail_mask();
+        let current = self.map.first().map_or(0, |&byte| {
+            if self.map.len() == 1 {
+                byte & tail_mask
+            } else {
+                byte
+            }
+        });
+        IterOnes {
+            map: &self.map,
+            byte_index: 0,
+            current,
+            tail_mask,
+        }
+    }
+
+    /// Returns an iterator over the underlying `u8` words of the bitmap.
+    ///
+    /// The final word has its padding bits above `bit_count` masked to 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut bitmap = bitmap::Bitmap::new(10);
+    /// bitmap.set(0);
+    /// bitmap.set(9);
+    /// let words: Vec<u8> = bitmap.chunks().collect();
+    /// assert_eq!(words, vec![0b0000_0001, 0b0000_0010]);
+    /// ```
+    ///
+    pub fn chunks(&self) -> Chunks<'_> {
+        Chunks {
+            map: &self.map,
+            byte_index: 0,
+            tail_mask: self.tail_mask(),
+        }
+    }
+
+    /// Returns a mask selecting the bits of the final byte of `map` that
+    /// lie within `bit_count`.
+    ///
+    /// The high bits of the last byte beyond `bit_count` are padding and must
+    /// always read as 0. Operations that can flip those bits (such as `Not`)
+    /// use this mask to clear them again.
+    #[inline]
+    fn tail_mask(&self) -> u8 {
+        match self.bit_count % 8 {
+            0 => 0xFF,
+            rem => (1 << rem) - 1,
+        }
+    }
+
+    /// Returns the mask of the bits of `byte` that fall inside the half-open
+    /// bit range `start..end`.
+    ///
+    /// A fully-covered middle byte yields `0xFF`; head and tail bytes yield a
+    /// narrower mask so only the bits inside the range are touched.
+    #[inline]
+    fn range_mask(start: usize, end: usize, byte: usize) -> u8 {
+        let base = byte * 8;
+        let low = start.max(base) - base;
+        let high = end.min(base + 8) - base;
+        (((1u16 << (high - low)) - 1) << low) as u8
+    }
+
+    /// Counts the bits set to 1 by scanning `map`, ignoring the padding bits
+    /// above `bit_count`.
+    ///
+    /// Used to rebuild `len` after operations that rewrite the buffer
+    /// wholesale, such as the bitwise set operations.
+    fn popcount(&self) -> usize {
+        if self.map.is_empty() {
+            return 0;
+        }
+        let mask = self.tail_mask();
+        let (last, head) = self.map.split_last().unwrap();
+        let mut count: u32 = head.iter().map(|byte| byte.count_ones()).sum();
+        count += (last & mask).count_ones();
+        count as usize
+    }
+}
+
+/// An iterator over the indices of the set bits of a [`Bitmap`].
+///
+/// Created by [`Bitmap::iter_ones`].
+pub struct IterOnes<'a> {
+    map: &'a [u8],
+    byte_index: usize,
+    current: u8,
+    tail_mask: u8,
+}
+
+impl Iterator for IterOnes<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            if self.current != 0 {
+                let lowest = self.current & self.current.wrapping_neg();
+                let offset = lowest.trailing_zeros() as usize;
+                self.current ^= lowest;
+                return Some(self.byte_index * 8 + offset);
+            }
+            self.byte_index += 1;
+            if self.byte_index >= self.map.len() {
+                return None;
+            }
+            self.current = if self.byte_index == self.map.len() - 1 {
+                self.map[self.byte_index] & self.tail_mask
+            } else {
+                self.map[self.byte_index]
+            };
+        }
+    }
+}
+
+/// An iterator over the underlying `u8` words of a [`Bitmap`].
+///
+/// Created by [`Bitmap::chunks`].
+pub struct Chunks<'a> {
+    map: &'a [u8],
+    byte_index: usize,
+    tail_mask: u8,
+}
+
+impl Iterator for Chunks<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.byte_index >= self.map.len() {
+            return None;
+        }
+        let byte = self.map[self.byte_index];
+        let word = if self.byte_index == self.map.len() - 1 {
+            byte & self.tail_mask
+        } else {
+            byte
+        };
+        self.byte_index += 1;
+        Some(word)
+    }
+}
+
+/// Intersects `self` with `rhs` in place, keeping only the bits set in both.
+///
+/// # Panics
+///
+/// Panics if the two bitmaps do not share the same `bit_count`.
+impl BitAndAssign<&Bitmap> for Bitmap {
+    fn bitand_assign(&mut self, rhs: &Bitmap) {
+        assert_eq!(self.bit_count, rhs.bit_count);
+        for (lhs, rhs) in self.map.iter_mut().zip(rhs.map.iter()) {
+            lhs.bitand_assign(*rhs);
+        }
+        self.len = self.popcount();
+    }
+}
+
+/// Unions `self` with `rhs` in place, setting every bit set in either.
+///
+/// # Panics
+///
+/// Panics if the two bitmaps do not share the same `bit_count`.
+impl BitOrAssign<&Bitmap> for Bitmap {
+    fn bitor_assign(&mut self, rhs: &Bitmap) {
+        assert_eq!(self.bit_count, rhs.bit_count);
+        for (lhs, rhs) in self.map.iter_mut().zip(rhs.map.iter()) {
+            lhs.bitor_assign(*rhs);
+        }
+        self.len = self.popcount();
+    }
+}
+
+/// Takes the symmetric difference of `self` and `rhs` in place.
+///
+/// # Panics
+///
+/// Panics if the two bitmaps do not share the same `bit_count`.
+impl BitXorAssign<&Bitmap> for Bitmap {
+    fn bitxor_assign(&mut self, rhs: &Bitmap) {
+        assert_eq!(self.bit_count, rhs.bit_count);
+        for (lhs, rhs) in self.map.iter_mut().zip(rhs.map.iter()) {
+            lhs.bitxor_assign(*rhs);
+        }
+        self.len = self.popcount();
+    }
+}
+
+/// Returns the intersection of `self` and `rhs`.
+///
+/// # Panics
+///
+/// Panics if the two bitmaps do not share the same `bit_count`.
+impl BitAnd<&Bitmap> for Bitmap {
+    type Output = Bitmap;
+
+    fn bitand(mut self, rhs: &Bitmap) -> Bitmap {
+        self.bitand_assign(rhs);
+        self
+    }
+}
+
+/// Returns the union of `self` and `rhs`.
+///
+/// # Panics
+///
+/// Panics if the two bitmaps do not share the same `bit_count`.
+impl BitOr<&Bitmap> for Bitmap {
+    type Output = Bitmap;
+
+    fn bitor(mut self, rhs: &Bitmap) -> Bitmap {
+        self.bitor_assign(rhs);
+        self
+    }
+}
+
+/// Returns the symmetric difference of `self` and `rhs`.
+///
+/// # Panics
+///
+/// Panics if the two bitmaps do not share the same `bit_count`.
+impl BitXor<&Bitmap> for Bitmap {
+    type Output = Bitmap;
+
+    fn bitxor(mut self, rhs: &Bitmap) -> Bitmap {
+        self.bitxor_assign(rhs);
+        self
+    }
+}
+
+/// Returns the complement of `self`, flipping every bit within `bit_count`.
+///
+/// The padding bits above `bit_count` in the final byte are masked back to 0
+/// so they do not leak into later `count_ones`/iteration queries.
+impl Not for Bitmap {
+    type Output = Bitmap;
+
+    fn not(mut self) -> Bitmap {
+        let mask = self.tail_mask();
+        for byte in self.map.iter_mut() {
+            *byte = !*byte;
+        }
+        if let Some(last) = self.map.last_mut() {
+            last.bitand_assign(mask);
+        }
+        self.len = self.popcount();
+        self
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Bitmap;
+    use super::{Bitmap, BitmapError};
 
     #[test]
     pub fn test1() {
@@ -198,4 +905,311 @@ mod tests {
         bmap.set(8);
         check_false_except(&bmap, 8);
     }
+
+    #[test]
+    pub fn test_bitwise() {
+        let mut a = Bitmap::new(64);
+        a.set(1);
+        a.set(10);
+        let mut b = Bitmap::new(64);
+        b.set(10);
+        b.set(20);
+
+        let and = a & &b;
+        assert_eq!(and.get(10), true);
+        assert_eq!(and.get(1), false);
+        assert_eq!(and.get(20), false);
+    }
+
+    #[test]
+    pub fn test_bitor() {
+        let mut a = Bitmap::new(64);
+        a.set(1);
+        let mut b = Bitmap::new(64);
+        b.set(20);
+        let c = a | &b;
+        assert_eq!(c.get(1), true);
+        assert_eq!(c.get(20), true);
+        assert_eq!(c.get(5), false);
+    }
+
+    #[test]
+    pub fn test_bitxor() {
+        let mut a = Bitmap::new(64);
+        a.set(1);
+        a.set(10);
+        let mut b = Bitmap::new(64);
+        b.set(10);
+        b.set(20);
+        let c = a ^ &b;
+        assert_eq!(c.get(1), true);
+        assert_eq!(c.get(10), false);
+        assert_eq!(c.get(20), true);
+    }
+
+    #[test]
+    pub fn test_not_masks_padding() {
+        let mut a = Bitmap::new(10);
+        a.set(0);
+        let c = !a;
+        assert_eq!(c.get(0), false);
+        assert_eq!(c.get(1), true);
+        assert_eq!(c.get(9), true);
+        // The six padding bits above bit 9 must stay 0.
+        assert_eq!(c.as_slice(), &[0b1111_1110, 0b0000_0011]);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn test_bitand_mismatch_panic() {
+        let a = Bitmap::new(64);
+        let b = Bitmap::new(32);
+        let _ = a & &b;
+    }
+
+    #[test]
+    pub fn test_count_ones() {
+        let mut bmap = Bitmap::new(10);
+        assert_eq!(bmap.count_ones(), 0);
+        bmap.set(1);
+        bmap.set(7);
+        bmap.set(9);
+        assert_eq!(bmap.count_ones(), 3);
+    }
+
+    #[test]
+    pub fn test_count_zeros() {
+        let mut bmap = Bitmap::new(10);
+        bmap.set(1);
+        bmap.set(7);
+        assert_eq!(bmap.count_zeros(), 8);
+    }
+
+    #[test]
+    pub fn test_rank() {
+        let mut bmap = Bitmap::new(64);
+        bmap.set(1);
+        bmap.set(7);
+        bmap.set(20);
+        assert_eq!(bmap.rank(0), 0);
+        assert_eq!(bmap.rank(1), 1);
+        assert_eq!(bmap.rank(7), 2);
+        assert_eq!(bmap.rank(19), 2);
+        assert_eq!(bmap.rank(20), 3);
+    }
+
+    #[test]
+    pub fn test_select() {
+        let mut bmap = Bitmap::new(64);
+        bmap.set(1);
+        bmap.set(7);
+        bmap.set(20);
+        assert_eq!(bmap.select(0), Some(1));
+        assert_eq!(bmap.select(1), Some(7));
+        assert_eq!(bmap.select(2), Some(20));
+        assert_eq!(bmap.select(3), None);
+    }
+
+    #[test]
+    pub fn test_iter_ones() {
+        let mut bmap = Bitmap::new(64);
+        bmap.set(1);
+        bmap.set(7);
+        bmap.set(8);
+        bmap.set(63);
+        let ones: Vec<usize> = bmap.iter_ones().collect();
+        assert_eq!(ones, vec![1, 7, 8, 63]);
+    }
+
+    #[test]
+    pub fn test_iter_ones_masks_padding() {
+        let mut bmap = Bitmap::new(10);
+        bmap.set(9);
+        // Only bit 9 is within range; padding indices must never appear.
+        let ones: Vec<usize> = bmap.iter_ones().collect();
+        assert_eq!(ones, vec![9]);
+    }
+
+    #[test]
+    pub fn test_chunks() {
+        let mut bmap = Bitmap::new(10);
+        bmap.set(0);
+        bmap.set(9);
+        let words: Vec<u8> = bmap.chunks().collect();
+        assert_eq!(words, vec![0b0000_0001, 0b0000_0010]);
+    }
+
+    #[test]
+    pub fn test_try_from_bytes() {
+        let bmap = Bitmap::try_from_bytes(vec![0b0000_0101, 0b0000_0001], 16).unwrap();
+        assert_eq!(bmap.get(0), true);
+        assert_eq!(bmap.get(2), true);
+        assert_eq!(bmap.get(8), true);
+        assert_eq!(bmap.get(1), false);
+    }
+
+    #[test]
+    pub fn test_try_from_bytes_masks_padding() {
+        // bit_count is 10, so only bits 8 and 9 of the second byte are valid.
+        let bmap = Bitmap::try_from_bytes(vec![0x00, 0b1111_1111], 10).unwrap();
+        assert_eq!(bmap.get(8), true);
+        assert_eq!(bmap.get(9), true);
+        assert_eq!(bmap.as_slice(), &[0x00, 0b0000_0011]);
+        assert_eq!(bmap.count_ones(), 2);
+    }
+
+    #[test]
+    pub fn test_try_from_bytes_length_mismatch() {
+        let err = Bitmap::try_from_bytes(vec![0x00], 16).unwrap_err();
+        assert_eq!(
+            err,
+            BitmapError::LengthMismatch {
+                expected: 2,
+                found: 1,
+            }
+        );
+    }
+
+    #[test]
+    pub fn test_as_slice() {
+        let mut bmap = Bitmap::new(8);
+        bmap.set(0);
+        bmap.set(2);
+        assert_eq!(bmap.as_slice(), &[0b0000_0101]);
+    }
+
+    #[test]
+    pub fn test_set_range() {
+        let mut bmap = Bitmap::new(32);
+        bmap.set_range(4, 20);
+        assert_eq!(bmap.get(3), false);
+        for bit in 4..20 {
+            assert_eq!(bmap.get(bit), true);
+        }
+        assert_eq!(bmap.get(20), false);
+        assert_eq!(bmap.count_ones(), 16);
+    }
+
+    #[test]
+    pub fn test_set_range_empty() {
+        let mut bmap = Bitmap::new(32);
+        bmap.set_range(5, 5);
+        assert_eq!(bmap.count_ones(), 0);
+    }
+
+    #[test]
+    pub fn test_unset_range() {
+        let mut bmap = Bitmap::new(32);
+        bmap.set_range(0, 32);
+        bmap.unset_range(4, 20);
+        assert_eq!(bmap.get(3), true);
+        for bit in 4..20 {
+            assert_eq!(bmap.get(bit), false);
+        }
+        assert_eq!(bmap.get(20), true);
+        assert_eq!(bmap.count_ones(), 16);
+    }
+
+    #[test]
+    pub fn test_flip() {
+        let mut bmap = Bitmap::new(10);
+        bmap.flip(5);
+        assert_eq!(bmap.get(5), true);
+        bmap.flip(5);
+        assert_eq!(bmap.get(5), false);
+    }
+
+    #[test]
+    pub fn test_flip_range() {
+        let mut bmap = Bitmap::new(32);
+        bmap.set(5);
+        bmap.flip_range(4, 8);
+        assert_eq!(bmap.get(4), true);
+        assert_eq!(bmap.get(5), false);
+        assert_eq!(bmap.get(6), true);
+        assert_eq!(bmap.get(7), true);
+    }
+
+    #[test]
+    pub fn test_flip_range_preserves_padding() {
+        let mut bmap = Bitmap::new(10);
+        bmap.flip_range(0, 10);
+        assert_eq!(bmap.count_ones(), 10);
+        // Padding bits above bit 9 must stay 0.
+        assert_eq!(bmap.as_slice(), &[0b1111_1111, 0b0000_0011]);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn test_set_range_out_of_bounds() {
+        let mut bmap = Bitmap::new(32);
+        bmap.set_range(0, 33);
+    }
+
+    #[test]
+    pub fn test_set_reports_previous() {
+        let mut bmap = Bitmap::new(64);
+        assert_eq!(bmap.set(10), false);
+        assert_eq!(bmap.set(10), true);
+        assert_eq!(bmap.unset(10), true);
+        assert_eq!(bmap.unset(10), false);
+    }
+
+    #[test]
+    pub fn test_len_tracking() {
+        let mut bmap = Bitmap::new(64);
+        assert_eq!(bmap.is_empty(), true);
+        bmap.set(10);
+        bmap.set(10);
+        bmap.set(20);
+        assert_eq!(bmap.count_ones(), 2);
+        assert_eq!(bmap.is_empty(), false);
+        bmap.unset(10);
+        assert_eq!(bmap.count_ones(), 1);
+        bmap.flip(30);
+        bmap.flip(20);
+        assert_eq!(bmap.count_ones(), 1);
+    }
+
+    #[test]
+    pub fn test_len_tracking_ranges() {
+        let mut bmap = Bitmap::new(64);
+        bmap.set_range(4, 20);
+        assert_eq!(bmap.count_ones(), 16);
+        bmap.unset_range(4, 8);
+        assert_eq!(bmap.count_ones(), 12);
+        bmap.flip_range(0, 64);
+        assert_eq!(bmap.count_ones(), 64 - 12);
+    }
+
+    #[test]
+    pub fn test_len_tracking_bitwise() {
+        let mut a = Bitmap::new(64);
+        a.set_range(0, 16);
+        let mut b = Bitmap::new(64);
+        b.set_range(8, 24);
+        let c = a | &b;
+        assert_eq!(c.count_ones(), 24);
+        let mut d = Bitmap::new(64);
+        d.set_range(0, 16);
+        let e = d & &b;
+        assert_eq!(e.count_ones(), 8);
+    }
+
+    #[test]
+    pub fn test_first_last_index() {
+        let mut bmap = Bitmap::new(64);
+        assert_eq!(bmap.first_index(), None);
+        assert_eq!(bmap.last_index(), None);
+        bmap.set(7);
+        bmap.set(40);
+        assert_eq!(bmap.first_index(), Some(7));
+        assert_eq!(bmap.last_index(), Some(40));
+    }
+
+    #[test]
+    pub fn test_try_from_bytes_tracks_len() {
+        let bmap = Bitmap::try_from_bytes(vec![0b0000_0101, 0b0000_0001], 16).unwrap();
+        assert_eq!(bmap.count_ones(), 3);
+    }
 }